@@ -71,7 +71,7 @@ pub struct CompileCommand {
     #[clap(flatten)]
     pub common: SharedArgs,
 
-    /// Path to output file (PDF, PNG or SVG). Use `-` to write output to stdout.
+    /// Path to output file (PDF, PNG, SVG or TXT). Use `-` to write output to stdout.
     ///
     /// For output formats emitting one file per page (PNG & SVG), a page number template
     /// must be present if the source document renders to multiple pages. Use `{p}` for page
@@ -109,6 +109,19 @@ pub struct CompileCommand {
     #[arg(long = "ppi", default_value_t = 144.0)]
     pub ppi: f32,
 
+    /// Renders PNG pages on a transparent instead of a white background
+    ///
+    /// Pages with an explicit fill (set via `page(fill: ..)`) are unaffected.
+    #[arg(long = "transparent")]
+    pub transparent: bool,
+
+    /// Treats warnings as errors for the exit status
+    ///
+    /// The output is still written, but compilation is reported as failed if
+    /// any warnings were emitted.
+    #[arg(long = "deny-warnings")]
+    pub deny_warnings: bool,
+
     /// Produces performance timings of the compilation process (experimental)
     ///
     /// The resulting JSON file can be loaded into a tracing tool such as
@@ -397,8 +410,13 @@ pub struct FontArgs {
 /// Which format to use for diagnostics.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, ValueEnum)]
 pub enum DiagnosticFormat {
+    /// Annotated source snippets, similar to rustc.
     Human,
+    /// One line per diagnostic with its location.
     Short,
+    /// One JSON object per line, with byte offsets and zero-based line and
+    /// column positions.
+    Json,
 }
 
 impl Display for DiagnosticFormat {
@@ -442,6 +460,7 @@ pub enum OutputFormat {
     Pdf,
     Png,
     Svg,
+    Txt,
 }
 
 impl Display for OutputFormat {
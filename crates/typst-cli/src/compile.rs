@@ -8,6 +8,7 @@ use codespan_reporting::term;
 use ecow::{eco_format, eco_vec, EcoString, EcoVec};
 use parking_lot::RwLock;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use serde::Serialize;
 use typst::diag::{bail, FileError, Severity, SourceDiagnostic, StrResult, Warned};
 use typst::foundations::{Datetime, Smart};
 use typst::layout::{Frame, PageRanges};
@@ -39,6 +40,7 @@ impl CompileCommand {
                     OutputFormat::Pdf => "pdf",
                     OutputFormat::Png => "png",
                     OutputFormat::Svg => "svg",
+                    OutputFormat::Txt => "txt",
                 },
             ))
         })
@@ -55,6 +57,7 @@ impl CompileCommand {
                 Some(ext) if ext.eq_ignore_ascii_case("pdf") => OutputFormat::Pdf,
                 Some(ext) if ext.eq_ignore_ascii_case("png") => OutputFormat::Png,
                 Some(ext) if ext.eq_ignore_ascii_case("svg") => OutputFormat::Svg,
+                Some(ext) if ext.eq_ignore_ascii_case("txt") => OutputFormat::Txt,
                 _ => bail!("could not infer output format for path {}.\nconsider providing the format manually with `--format/-f`", output.display()),
             }
         } else {
@@ -130,6 +133,10 @@ pub fn compile_once(
             print_diagnostics(world, &[], &warnings, command.common.diagnostic_format)
                 .map_err(|err| eco_format!("failed to print diagnostics ({err})"))?;
 
+            if command.deny_warnings && !warnings.is_empty() {
+                set_failed();
+            }
+
             write_make_deps(world, command)?;
 
             if let Some(open) = command.open.take() {
@@ -175,6 +182,7 @@ fn export(
             export_image(world, document, command, watching, ImageExportFormat::Svg)
         }
         OutputFormat::Pdf => export_pdf(document, command),
+        OutputFormat::Txt => export_text(document, command),
     }
 }
 
@@ -192,6 +200,16 @@ fn export_pdf(document: &Document, command: &CompileCommand) -> StrResult<()> {
     Ok(())
 }
 
+/// Export the plain text of the document.
+fn export_text(document: &Document, command: &CompileCommand) -> StrResult<()> {
+    let text = document.plain_text(command.exported_page_ranges());
+    command
+        .output()
+        .write(text.as_bytes())
+        .map_err(|err| eco_format!("failed to write text file ({err})"))?;
+    Ok(())
+}
+
 /// Convert [`chrono::DateTime`] to [`Datetime`]
 fn convert_datetime(date_time: chrono::DateTime<chrono::Utc>) -> Option<Datetime> {
     Datetime::from_ymd_hms(
@@ -330,7 +348,12 @@ fn export_image_page(
 ) -> StrResult<()> {
     match fmt {
         ImageExportFormat::Png => {
-            let pixmap = typst_render::render(frame, command.ppi / 72.0, Color::WHITE);
+            let fill = if command.transparent {
+                Color::WHITE.with_alpha(0.0)
+            } else {
+                Color::WHITE
+            };
+            let pixmap = typst_render::render(frame, command.ppi / 72.0, fill);
             let buf = pixmap
                 .encode_png()
                 .map_err(|err| eco_format!("failed to encode PNG file ({err})"))?;
@@ -535,6 +558,10 @@ pub fn print_diagnostics(
     warnings: &[SourceDiagnostic],
     diagnostic_format: DiagnosticFormat,
 ) -> Result<(), codespan_reporting::files::Error> {
+    if diagnostic_format == DiagnosticFormat::Json {
+        return print_json_diagnostics(world, errors, warnings);
+    }
+
     let mut config = term::Config { tab_width: 2, ..Default::default() };
     if diagnostic_format == DiagnosticFormat::Short {
         config.display_style = term::DisplayStyle::Short;
@@ -571,6 +598,92 @@ pub fn print_diagnostics(
     Ok(())
 }
 
+/// Print diagnostic messages as JSON lines.
+fn print_json_diagnostics(
+    world: &SystemWorld,
+    errors: &[SourceDiagnostic],
+    warnings: &[SourceDiagnostic],
+) -> Result<(), codespan_reporting::files::Error> {
+    let mut out = terminal::out();
+    for diagnostic in warnings.iter().chain(errors) {
+        let json = JsonDiagnostic {
+            severity: match diagnostic.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+            },
+            message: &diagnostic.message,
+            span: json_span(world, diagnostic.span),
+            hints: &diagnostic.hints,
+            trace: diagnostic
+                .trace
+                .iter()
+                .map(|point| JsonTracepoint {
+                    message: point.v.to_string(),
+                    span: json_span(world, point.span),
+                })
+                .collect(),
+        };
+
+        serde_json::to_writer(&mut out, &json).map_err(io::Error::from)?;
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+/// A diagnostic in the JSON diagnostic format.
+#[derive(Serialize)]
+struct JsonDiagnostic<'a> {
+    severity: &'static str,
+    message: &'a str,
+    span: Option<JsonSpan>,
+    hints: &'a [EcoString],
+    trace: Vec<JsonTracepoint>,
+}
+
+/// A trace point in the JSON diagnostic format.
+#[derive(Serialize)]
+struct JsonTracepoint {
+    message: String,
+    span: Option<JsonSpan>,
+}
+
+/// A span in the JSON diagnostic format.
+#[derive(Serialize)]
+struct JsonSpan {
+    file: String,
+    start: JsonPosition,
+    end: JsonPosition,
+}
+
+/// A position in the JSON diagnostic format.
+#[derive(Serialize)]
+struct JsonPosition {
+    offset: usize,
+    line: usize,
+    column: usize,
+}
+
+/// Resolve a span into file and positions for JSON output.
+fn json_span(world: &SystemWorld, span: Span) -> Option<JsonSpan> {
+    let id = span.id()?;
+    let range = world.range(span)?;
+    let source = world.source(id).ok()?;
+    let position = |offset| {
+        Some(JsonPosition {
+            offset,
+            line: source.byte_to_line(offset)?,
+            column: source.byte_to_column(offset)?,
+        })
+    };
+
+    Some(JsonSpan {
+        file: codespan_reporting::files::Files::name(world, id).ok()?,
+        start: position(range.start)?,
+        end: position(range.end)?,
+    })
+}
+
 /// Create a label for a span.
 fn label(world: &SystemWorld, span: Span) -> Option<Label<FileId>> {
     Some(Label::primary(span.id()?, world.range(span)?))
@@ -12,7 +12,7 @@ use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher as _};
 use same_file::is_same_file;
 use typst::diag::{bail, StrResult};
 
-use crate::args::{CompileCommand, Input, Output};
+use crate::args::{CompileCommand, DiagnosticFormat, Input, Output};
 use crate::compile::compile_once;
 use crate::timings::Timer;
 use crate::world::{SystemWorld, WorldCreationError};
@@ -266,7 +266,14 @@ pub enum Status {
 
 impl Status {
     /// Clear the terminal and render the status message.
+    ///
+    /// Nothing is printed when diagnostics are emitted as JSON, so that the
+    /// output stays machine-readable.
     pub fn print(&self, command: &CompileCommand) -> io::Result<()> {
+        if command.common.diagnostic_format == DiagnosticFormat::Json {
+            return Ok(());
+        }
+
         let output = command.output();
         let timestamp = chrono::offset::Local::now().format("%H:%M:%S");
         let color = self.color();
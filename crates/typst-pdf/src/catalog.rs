@@ -72,6 +72,11 @@ pub fn write_catalog(
         xmp.creator([joined.as_str()]);
     }
 
+    if let Some(description) = &ctx.document.description {
+        info.subject(TextStr(description));
+        xmp.description([(None, description.as_str())]);
+    }
+
     let creator = eco_format!("Typst {}", env!("CARGO_PKG_VERSION"));
     info.creator(TextStr(&creator));
     xmp.creator_tool(&creator);
@@ -2,7 +2,8 @@ use ecow::EcoString;
 use ttf_parser::OutlineBuilder;
 use typst::layout::{Abs, Ratio, Size, Transform};
 use typst::visualize::{
-    FixedStroke, Geometry, LineCap, LineJoin, Paint, Path, PathItem, RelativeTo, Shape,
+    FillRule, FixedStroke, Geometry, LineCap, LineJoin, Paint, Path, PathItem,
+    RelativeTo, Shape,
 };
 
 use crate::paint::ColorEncode;
@@ -20,6 +21,9 @@ impl SVGRenderer {
                 self.shape_fill_size(state, paint, shape),
                 self.shape_paint_transform(state, paint, shape),
             );
+            if shape.fill_rule == FillRule::EvenOdd {
+                self.xml.write_attribute("fill-rule", "evenodd");
+            }
         } else {
             self.xml.write_attribute("fill", "none");
         }
@@ -1,7 +1,10 @@
+use serde::Serialize;
+
 /// A syntactical building block of a Typst file.
 ///
 /// Can be created by the lexer or by the parser.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize)]
+#[serde(rename_all = "kebab-case")]
 #[repr(u8)]
 pub enum SyntaxKind {
     /// The end of token stream.
@@ -4,6 +4,7 @@ use std::rc::Rc;
 use std::sync::Arc;
 
 use ecow::{eco_format, eco_vec, EcoString, EcoVec};
+use serde::ser::{Serialize, SerializeMap, Serializer};
 
 use crate::ast::AstNode;
 use crate::{FileId, Span, SyntaxKind};
@@ -314,6 +315,27 @@ impl SyntaxNode {
     }
 }
 
+/// Serializes the node with its kind, span number and text or children.
+///
+/// Error nodes additionally carry their message and hints.
+impl Serialize for SyntaxNode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("kind", &self.kind())?;
+        map.serialize_entry("span", &self.span().number())?;
+        match &self.0 {
+            Repr::Leaf(leaf) => map.serialize_entry("text", &leaf.text)?,
+            Repr::Inner(inner) => map.serialize_entry("children", &inner.children)?,
+            Repr::Error(node) => {
+                map.serialize_entry("text", &node.text)?;
+                map.serialize_entry("message", &node.error.message)?;
+                map.serialize_entry("hints", &node.error.hints)?;
+            }
+        }
+        map.end()
+    }
+}
+
 impl Debug for SyntaxNode {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match &self.0 {
@@ -1009,6 +1031,34 @@ mod tests {
     use super::*;
     use crate::Source;
 
+    #[test]
+    fn test_serialize_node() {
+        let mut error = SyntaxNode::error(SyntaxError::new("unexpected star"), "*");
+        error.hint("try escaping it");
+        let node = SyntaxNode::inner(
+            SyntaxKind::Markup,
+            vec![SyntaxNode::leaf(SyntaxKind::Text, "a"), error],
+        );
+
+        assert_eq!(
+            serde_json::to_value(&node).unwrap(),
+            serde_json::json!({
+                "kind": "markup",
+                "span": 1,
+                "children": [
+                    { "kind": "text", "span": 1, "text": "a" },
+                    {
+                        "kind": "error",
+                        "span": 1,
+                        "text": "*",
+                        "message": "unexpected star",
+                        "hints": ["try escaping it"],
+                    },
+                ],
+            }),
+        );
+    }
+
     #[test]
     fn test_linked_node() {
         let source = Source::detached("#set text(12pt, red)");
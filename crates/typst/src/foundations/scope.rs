@@ -76,16 +76,83 @@ impl<'a> Scopes<'a> {
 
     /// Try to access a variable mutably.
     pub fn get_mut(&mut self, var: &str) -> HintedStrResult<&mut Value> {
-        std::iter::once(&mut self.top)
-            .chain(&mut self.scopes.iter_mut().rev())
-            .find_map(|scope| scope.get_mut(var))
-            .ok_or_else(|| {
-                match self.base.and_then(|base| base.global.scope().get(var)) {
-                    Some(_) => cannot_mutate_constant(var),
-                    _ if var == "std" => cannot_mutate_constant(var),
-                    _ => unknown_variable(var),
-                }
-            })?
+        // Look up first and build the error afterwards, so that the error can
+        // borrow all scopes to suggest a similar name.
+        let local = std::iter::once(&self.top)
+            .chain(self.scopes.iter().rev())
+            .any(|scope| scope.get(var).is_some());
+
+        if local {
+            return std::iter::once(&mut self.top)
+                .chain(&mut self.scopes.iter_mut().rev())
+                .find_map(|scope| scope.get_mut(var))
+                .expect("variable should be in a local scope");
+        }
+
+        match self.base.and_then(|base| base.global.scope().get(var)) {
+            Some(_) => Err(cannot_mutate_constant(var)),
+            _ if var == "std" => Err(cannot_mutate_constant(var)),
+            // Standard library bindings can't be assigned to, so only local
+            // ones are worth suggesting.
+            _ => Err(self.hint_similar_in(unknown_variable(var), var, None)),
+        }
+    }
+
+    /// Add a hint to an unknown variable error that suggests a visible
+    /// binding with a similar name, if there is one.
+    ///
+    /// This is not done by [`get`](Self::get) itself because failed lookups
+    /// are also used to probe for captured variables, where the error is
+    /// discarded.
+    ///
+    /// Closure bodies are evaluated without a base, so the standard library
+    /// names are then taken from the given `library` instead.
+    #[cold]
+    pub fn hint_similar(
+        &self,
+        error: HintedString,
+        var: &str,
+        library: &Library,
+    ) -> HintedString {
+        let base = self.base.unwrap_or(library);
+        self.hint_similar_in(error, var, Some(base.global.scope()))
+    }
+
+    /// Like [`hint_similar`](Self::hint_similar), but for errors from
+    /// [`get_in_math`](Self::get_in_math).
+    #[cold]
+    pub fn hint_similar_in_math(
+        &self,
+        error: HintedString,
+        var: &str,
+        library: &Library,
+    ) -> HintedString {
+        let base = self.base.unwrap_or(library);
+        self.hint_similar_in(error, var, Some(base.math.scope()))
+    }
+
+    /// Add a similar name hint, considering the given standard library scope.
+    fn hint_similar_in(
+        &self,
+        mut error: HintedString,
+        var: &str,
+        base: Option<&Scope>,
+    ) -> HintedString {
+        // Don't pile a suggestion onto a more specific hint.
+        if !error.hints().is_empty() {
+            return error;
+        }
+
+        let names = std::iter::once(&self.top)
+            .chain(self.scopes.iter().rev())
+            .chain(base)
+            .flat_map(Scope::names);
+
+        if let Some(similar) = most_similar(var, names) {
+            error.hint(eco_format!("did you mean `{similar}`?"));
+        }
+
+        error
     }
 
     /// Check if an std variable is shadowed.
@@ -120,6 +187,42 @@ fn unknown_variable(var: &str) -> HintedString {
     res
 }
 
+/// Find the name that is closest to `var` by edit distance, if any is close
+/// enough to likely be a typo.
+fn most_similar<'a>(
+    var: &str,
+    names: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let len = var.chars().count();
+    if len < 3 {
+        return None;
+    }
+
+    let max = len / 3;
+    names
+        .into_iter()
+        .map(|name| (name, edit_distance(var, name)))
+        .filter(|&(_, distance)| distance <= max)
+        .min_by_key(|&(_, distance)| distance)
+        .map(|(name, _)| name)
+}
+
+/// The Levenshtein distance between two strings, counted in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitute = prev[j] + usize::from(ca != cb);
+            curr[j + 1] = substitute.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
 /// A map from binding names to values.
 #[derive(Default, Clone)]
 pub struct Scope {
@@ -222,6 +325,11 @@ impl Scope {
         self.map.get(var)?.category
     }
 
+    /// Iterate over the names of all definitions.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.map.keys().map(EcoString::as_str)
+    }
+
     /// Iterate over all definitions.
     pub fn iter(&self) -> impl Iterator<Item = (&EcoString, &Value)> {
         self.map.iter().map(|(k, v)| (k, v.read()))
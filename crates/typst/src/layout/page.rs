@@ -6,7 +6,7 @@ use std::str::FromStr;
 
 use comemo::Track;
 
-use crate::diag::{bail, SourceResult};
+use crate::diag::{bail, warning, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
     cast, elem, AutoValue, Cast, Content, Context, Dict, Fold, Func, NativeElement,
@@ -392,6 +392,14 @@ impl Packed<PageElem> {
         }
 
         let area = size - margin.sum_by_axis();
+        if area.x <= Abs::zero() || area.y <= Abs::zero() {
+            engine.sink.warn(warning!(
+                self.span(),
+                "page margins leave no room for the content";
+                hint: "reduce the margins or increase the page size"
+            ));
+        }
+
         let mut regions = Regions::repeat(area, area.map(Abs::is_finite));
         regions.root = true;
 
@@ -936,6 +944,7 @@ papers! {
     (A11:  18.0,   26.0, "a11")
 
     // ISO 216 B Series
+    (ISO_B0: 1000.0, 1414.0, "iso-b0")
     (ISO_B1: 707.0, 1000.0, "iso-b1")
     (ISO_B2: 500.0,  707.0,  "iso-b2")
     (ISO_B3: 353.0,  500.0,  "iso-b3")
@@ -944,14 +953,21 @@ papers! {
     (ISO_B6: 125.0,  176.0,  "iso-b6")
     (ISO_B7:  88.0,  125.0,  "iso-b7")
     (ISO_B8:  62.0,   88.0,  "iso-b8")
+    (ISO_B9:  44.0,   62.0,  "iso-b9")
+    (ISO_B10: 31.0,   44.0,  "iso-b10")
 
     // ISO 216 C Series
+    (ISO_C0: 917.0, 1297.0, "iso-c0")
+    (ISO_C1: 648.0, 917.0, "iso-c1")
+    (ISO_C2: 458.0, 648.0, "iso-c2")
     (ISO_C3: 324.0, 458.0, "iso-c3")
     (ISO_C4: 229.0, 324.0, "iso-c4")
     (ISO_C5: 162.0, 229.0, "iso-c5")
     (ISO_C6: 114.0, 162.0, "iso-c6")
     (ISO_C7:  81.0, 114.0, "iso-c7")
     (ISO_C8:  57.0,  81.0, "iso-c8")
+    (ISO_C9:  40.0,  57.0, "iso-c9")
+    (ISO_C10: 28.0,  40.0, "iso-c10")
 
     // DIN D Series (extension to ISO)
     (DIN_D3: 272.0, 385.0, "din-d3")
@@ -1047,7 +1063,7 @@ papers! {
     (US_LETTER:         215.9,  279.4, "us-letter")
     (US_LEGAL:          215.9,  355.6, "us-legal")
     (US_TABLOID:        279.4,  431.8, "us-tabloid")
-    (US_EXECUTIVE:      84.15,  266.7, "us-executive")
+    (US_EXECUTIVE:     184.15,  266.7, "us-executive")
     (US_FOOLSCAP_FOLIO: 215.9,  342.9, "us-foolscap-folio")
     (US_STATEMENT:      139.7,  215.9, "us-statement")
     (US_LEDGER:         431.8,  279.4, "us-ledger")
@@ -7,8 +7,11 @@ use crate::foundations::{
     Value,
 };
 use crate::introspection::{Introspector, Locator, ManualPageCounter};
-use crate::layout::{Page, PageElem};
+use crate::layout::{
+    Abs, Frame, FrameItem, Page, PageElem, PageRanges, Point, Transform,
+};
 use crate::realize::StyleVec;
+use crate::text::TextItem;
 
 /// The root element of a document and its metadata.
 ///
@@ -40,6 +43,13 @@ pub struct DocumentElem {
     #[ghost]
     pub author: Author,
 
+    /// The document's description.
+    ///
+    /// This is embedded as the subject of the PDF. Like the title, it may be
+    /// arbitrary content, but is converted to plain text.
+    #[ghost]
+    pub description: Option<Content>,
+
     /// The document's keywords.
     #[ghost]
     pub keywords: Keywords,
@@ -111,6 +121,8 @@ impl Packed<DocumentElem> {
             pages,
             title: DocumentElem::title_in(styles).map(|content| content.plain_text()),
             author: DocumentElem::author_in(styles).0,
+            description: DocumentElem::description_in(styles)
+                .map(|content| content.plain_text()),
             keywords: DocumentElem::keywords_in(styles).0,
             date: DocumentElem::date_in(styles),
             introspector: Introspector::default(),
@@ -149,6 +161,8 @@ pub struct Document {
     pub title: Option<EcoString>,
     /// The document's author.
     pub author: Vec<EcoString>,
+    /// The document's description.
+    pub description: Option<EcoString>,
     /// The document's keywords.
     pub keywords: Vec<EcoString>,
     /// The document's creation date.
@@ -157,13 +171,202 @@ pub struct Document {
     pub introspector: Introspector,
 }
 
+impl Document {
+    /// Extracts the text of the laid-out document.
+    ///
+    /// Lines are separated by newlines and pages by blank lines. Only the pages
+    /// in `page_ranges` are included, or all pages if it is `None`. Hyphens
+    /// that were inserted by hyphenation are dropped and the hyphenated word is
+    /// joined back together.
+    ///
+    /// Text is visited in visual order. Right-to-left runs thus come out in
+    /// the order they are displayed, not in logical reading order, and an
+    /// inserted hyphen at the end of such a run is not detected.
+    pub fn plain_text(&self, page_ranges: Option<PageRanges>) -> EcoString {
+        let mut text = EcoString::new();
+        let pages = self
+            .pages
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| {
+                page_ranges
+                    .as_ref()
+                    .map_or(true, |ranges| ranges.includes_page_index(*i))
+            })
+            .map(|(_, page)| page);
+
+        for (i, page) in pages.enumerate() {
+            if i > 0 {
+                text.push_str("\n\n");
+            }
+            let mut extractor = TextExtractor::default();
+            extractor.frame(&page.frame, Transform::identity());
+            text.push_str(&extractor.text);
+        }
+        text
+    }
+}
+
+/// Collects the text of a frame in visual order.
+#[derive(Default)]
+struct TextExtractor {
+    /// The text collected so far.
+    text: EcoString,
+    /// The baseline end point and font size of the previous text item.
+    last: Option<(Point, Abs)>,
+    /// Whether the previous text item ended with an inserted hyphen.
+    hyphenated: bool,
+}
+
+impl TextExtractor {
+    /// Collect the text of a frame placed with the given transform.
+    fn frame(&mut self, frame: &Frame, ts: Transform) {
+        for (pos, item) in frame.items() {
+            let ts = ts.pre_concat(Transform::translate(pos.x, pos.y));
+            match item {
+                FrameItem::Group(group) => {
+                    self.frame(&group.frame, ts.pre_concat(group.transform))
+                }
+                FrameItem::Text(text) => self.text(text, ts),
+                _ => {}
+            }
+        }
+    }
+
+    /// Collect a single text item placed with the given transform.
+    fn text(&mut self, item: &TextItem, ts: Transform) {
+        let start = Point::zero().transform(ts);
+        if let Some((end, size)) = self.last {
+            // The text continues on a new line if it moves back to the left
+            // or vertically by more than a baseline shift.
+            let new_line = start.x + size / 2.0 < end.x || (start.y - end.y).abs() > size;
+            if new_line && !self.hyphenated {
+                self.text.push('\n');
+            }
+        }
+
+        self.text.push_str(&item.text);
+        self.last = Some((Point::with_x(item.width()).transform(ts), item.size));
+
+        // Inserted hyphens don't correspond to any text and thus have an empty
+        // range.
+        self.hyphenated = item.glyphs.last().is_some_and(|glyph| glyph.range.is_empty());
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::num::NonZeroUsize;
+    use std::ops::Range;
+
     use super::*;
+    use crate::foundations::Bytes;
+    use crate::layout::{Em, Size};
+    use crate::syntax::Span;
+    use crate::text::{Font, Glyph, Lang};
+    use crate::visualize::Color;
 
     #[test]
     fn test_document_is_send_and_sync() {
         fn ensure_send_and_sync<T: Send + Sync>() {}
         ensure_send_and_sync::<Document>();
     }
+
+    /// Build a text item with 5pt wide glyphs, optionally followed by an
+    /// inserted hyphen.
+    fn item(text: &str, hyphen: bool) -> TextItem {
+        let font = typst_dev_assets::fonts()
+            .flat_map(|data| Font::iter(Bytes::from_static(data)))
+            .next()
+            .unwrap();
+
+        let glyph = |range: Range<usize>| Glyph {
+            id: 0,
+            x_advance: Em::new(0.5),
+            x_offset: Em::zero(),
+            range: range.start as u16..range.end as u16,
+            span: (Span::detached(), 0),
+        };
+
+        let mut glyphs: Vec<_> =
+            text.char_indices().map(|(i, c)| glyph(i..i + c.len_utf8())).collect();
+        if hyphen {
+            glyphs.push(glyph(text.len()..text.len()));
+        }
+
+        TextItem {
+            font,
+            size: Abs::pt(10.0),
+            fill: Color::BLACK.into(),
+            stroke: None,
+            lang: Lang::ENGLISH,
+            region: None,
+            text: text.into(),
+            glyphs,
+        }
+    }
+
+    /// Build a document with one page per slice of `(x, y, item)` tuples.
+    fn document(pages: &[&[(f64, f64, TextItem)]]) -> Document {
+        let pages = pages
+            .iter()
+            .map(|items| {
+                let mut frame = Frame::soft(Size::splat(Abs::pt(100.0)));
+                for (x, y, item) in items.iter() {
+                    let pos = Point::new(Abs::pt(*x), Abs::pt(*y));
+                    frame.push(pos, FrameItem::Text(item.clone()));
+                }
+                Page { frame, numbering: None, number: 1 }
+            })
+            .collect();
+        Document { pages, ..Default::default() }
+    }
+
+    #[test]
+    fn test_plain_text_wrapped_paragraph() {
+        let doc = document(&[&[
+            (0.0, 10.0, item("Hello ", false)),
+            (30.0, 10.0, item("world", false)),
+            (0.0, 22.0, item("again", false)),
+        ]]);
+        assert_eq!(doc.plain_text(None), "Hello world\nagain");
+    }
+
+    #[test]
+    fn test_plain_text_rejoins_hyphenated_word() {
+        let doc = document(&[&[
+            (0.0, 10.0, item("hyphen", true)),
+            (0.0, 22.0, item("ation", false)),
+        ]]);
+        assert_eq!(doc.plain_text(None), "hyphenation");
+    }
+
+    #[test]
+    fn test_plain_text_keeps_explicit_hyphen() {
+        let doc = document(&[&[
+            (0.0, 10.0, item("well-", false)),
+            (0.0, 22.0, item("known", false)),
+        ]]);
+        assert_eq!(doc.plain_text(None), "well-\nknown");
+    }
+
+    #[test]
+    fn test_plain_text_separates_pages() {
+        let doc = document(&[
+            &[(0.0, 10.0, item("first", false))],
+            &[(0.0, 10.0, item("second", false))],
+        ]);
+        assert_eq!(doc.plain_text(None), "first\n\nsecond");
+    }
+
+    #[test]
+    fn test_plain_text_selects_pages() {
+        let doc = document(&[
+            &[(0.0, 10.0, item("first", false))],
+            &[(0.0, 10.0, item("second", false))],
+            &[(0.0, 10.0, item("third", false))],
+        ]);
+        let ranges = PageRanges::new(vec![NonZeroUsize::new(2)..=None]);
+        assert_eq!(doc.plain_text(Some(ranges)), "second\n\nthird");
+    }
 }
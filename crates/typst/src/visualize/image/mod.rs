@@ -13,7 +13,7 @@ use std::sync::Arc;
 use comemo::Tracked;
 use ecow::EcoString;
 
-use crate::diag::{bail, At, SourceResult, StrResult};
+use crate::diag::{bail, At, HintedStrResult, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
     cast, elem, func, scope, Bytes, Cast, Content, NativeElement, Packed, Show, Smart,
@@ -261,7 +261,7 @@ fn layout_image(
 }
 
 /// Determine the image format based on path and data.
-fn determine_format(path: &str, data: &Readable) -> StrResult<ImageFormat> {
+fn determine_format(path: &str, data: &Readable) -> HintedStrResult<ImageFormat> {
     let ext = std::path::Path::new(path)
         .extension()
         .and_then(OsStr::to_str)
@@ -277,7 +277,10 @@ fn determine_format(path: &str, data: &Readable) -> StrResult<ImageFormat> {
             Readable::Str(_) => ImageFormat::Vector(VectorFormat::Svg),
             Readable::Bytes(bytes) => match RasterFormat::detect(bytes) {
                 Some(f) => ImageFormat::Raster(f),
-                None => bail!("unknown image format"),
+                None => bail!(
+                    "unknown image format";
+                    hint: "supported formats are PNG, JPEG, GIF, and SVG"
+                ),
             },
         },
     })
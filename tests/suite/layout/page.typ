@@ -26,6 +26,10 @@
 // Flipped predefined paper.
 #[#set page(paper: "a11", flipped: true);Flipped A11]
 
+--- page-set-paper-us-executive ---
+#set page(paper: "us-executive")
+#context test(page.width, 184.15mm)
+
 --- page-set-in-container ---
 #box[
   // Error: 4-18 page configuration is not allowed inside of containers
@@ -131,6 +135,16 @@
 // Error: 20-23 must be `left` or `right`
 #set page(binding: top)
 
+--- page-margin-exceeds-size ---
+// Warning: 2-50 page margins leave no room for the content
+// Hint: 2-50 reduce the margins or increase the page size
+#page(width: 120pt, height: 20pt, margin: 70pt)[]
+
+--- page-margin-fills-size ---
+// Warning: 2-63 page margins leave no room for the content
+// Hint: 2-63 reduce the margins or increase the page size
+#page(width: 120pt, height: 20pt, margin: (x: 60pt, y: 0pt))[]
+
 --- page-marginals ---
 #set page(
   paper: "a8",
@@ -9,6 +9,9 @@ What's up?
 // This, too.
 #set document(author: ("A", "B"), date: datetime.today())
 
+--- document-set-description ---
+#set document(description: [A document about *documents*.])
+
 --- document-date-bad ---
 // Error: 21-28 expected datetime, none, or auto, found string
 #set document(date: "today")
@@ -158,3 +158,26 @@ Three
 // Error: 21-24 expected pattern, found keyword `let`
 // Hint: 21-24 keyword `let` is not allowed as an identifier; try `let_` instead
 #let (context, foo, let) = (5, 6, 7)
+
+--- let-unknown-variable-similar ---
+#let value = 1
+// Error: 2-6 unknown variable: valu
+// Hint: 2-6 did you mean `value`?
+#valu
+
+--- let-unknown-variable-similar-std ---
+// Error: 2-8 unknown variable: heding
+// Hint: 2-8 did you mean `heading`?
+#heding[A]
+
+--- let-unknown-variable-similar-closure ---
+// Error: 12-18 unknown variable: heding
+// Hint: 12-18 did you mean `heading`?
+#let f() = heding[A]
+#f()
+
+--- let-unknown-variable-similar-mutable ---
+#let value = 1
+// Error: 3-7 unknown variable: valu
+// Hint: 3-7 did you mean `value`?
+#(valu = 2)
@@ -78,6 +78,7 @@ A #box(image("/assets/images/tiger.jpg", height: 1cm, width: 80%)) B
 
 --- image-bad-format ---
 // Error: 2-22 unknown image format
+// Hint: 2-22 supported formats are PNG, JPEG, GIF, and SVG
 #image("./image.typ")
 
 --- image-bad-svg ---
@@ -39,6 +39,29 @@
   ),
 )
 
+--- path-fill-rule ---
+#let shape = path.with(
+  fill: black,
+  closed: true,
+  (0pt, 0pt), (20pt, 0pt), (20pt, 20pt), (5pt, 20pt),
+  (5pt, 5pt), (15pt, 5pt), (15pt, 15pt), (0pt, 15pt),
+)
+
+#stack(
+  dir: ltr,
+  spacing: 10pt,
+  shape(fill-rule: "non-zero"),
+  shape(fill-rule: "even-odd"),
+)
+
+--- path-fill-rule-field ---
+#context test(path.fill-rule, "non-zero")
+#test(path(fill-rule: "even-odd", (0pt, 0pt)).fill-rule, "even-odd")
+
+--- path-bad-fill-rule ---
+// Error: 18-26 expected "non-zero" or "even-odd"
+#path(fill-rule: "evn-od", (0pt, 0pt))
+
 --- path-bad-vertex ---
 // Error: 7-9 path vertex must have 1, 2, or 3 points
 #path(())
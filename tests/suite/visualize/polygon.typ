@@ -46,6 +46,20 @@
     (0pt, 20pt), (15pt, 0pt), (0pt, 40pt), (15pt, 45pt)),
 )
 
+--- polygon-fill-rule ---
+#let shape = polygon.with(
+  fill: black,
+  (0pt, 0pt), (20pt, 0pt), (20pt, 20pt), (5pt, 20pt),
+  (5pt, 5pt), (15pt, 5pt), (15pt, 15pt), (0pt, 15pt),
+)
+
+#stack(
+  dir: ltr,
+  spacing: 10pt,
+  shape(fill-rule: "non-zero"),
+  shape(fill-rule: "even-odd"),
+)
+
 --- polygon-bad-point-array ---
 // Error: 10-17 point array must contain exactly two entries
 #polygon((50pt,))